@@ -1,9 +1,17 @@
 use std::str::FromStr;
 
+#[cfg(feature = "csv")]
+use polars_core::prelude::{DataType, Schema, TimeUnit};
 use polars_core::prelude::{PolarsError, PolarsResult};
+#[cfg(any(feature = "csv", feature = "parquet", feature = "ipc"))]
+use polars_lazy::dsl::concat;
 #[cfg(feature = "csv")]
 use polars_lazy::prelude::LazyCsvReader;
 use polars_lazy::prelude::LazyFrame;
+#[cfg(feature = "parquet")]
+use polars_lazy::prelude::ScanArgsParquet;
+#[cfg(feature = "ipc")]
+use polars_lazy::prelude::ScanArgsIpc;
 use sqlparser::ast::{FunctionArg, FunctionArgExpr};
 
 /// Table functions that are supported by Polars
@@ -26,6 +34,18 @@ pub(crate) enum PolarsTableFunctions {
     /// ```
     #[cfg(feature = "ipc")]
     ReadIpc,
+    /// SQL 'read_ndjson' function (alias 'read_json')
+    /// ```sql
+    /// SELECT * FROM read_ndjson('path/to/file.ndjson')
+    /// ```
+    #[cfg(feature = "json")]
+    ReadNdjson,
+    /// SQL 'read_avro' function
+    /// ```sql
+    /// SELECT * FROM read_avro('path/to/file.avro')
+    /// ```
+    #[cfg(feature = "avro")]
+    ReadAvro,
 }
 
 impl FromStr for PolarsTableFunctions {
@@ -39,6 +59,10 @@ impl FromStr for PolarsTableFunctions {
             "read_parquet" => Ok(PolarsTableFunctions::ReadParquet),
             #[cfg(feature = "ipc")]
             "read_ipc" => Ok(PolarsTableFunctions::ReadIpc),
+            #[cfg(feature = "json")]
+            "read_ndjson" | "read_json" => Ok(PolarsTableFunctions::ReadNdjson),
+            #[cfg(feature = "avro")]
+            "read_avro" => Ok(PolarsTableFunctions::ReadAvro),
             _ => Err(PolarsError::ComputeError(
                 format!("'{}' is not a supported table function", s).into(),
             )),
@@ -46,6 +70,63 @@ impl FromStr for PolarsTableFunctions {
     }
 }
 
+/// Options accepted by the `read_csv` table function, parsed from its named arguments.
+#[cfg(feature = "csv")]
+#[derive(Default)]
+struct CsvReadOptions {
+    delimiter: Option<u8>,
+    has_header: Option<bool>,
+    infer_schema_length: Option<usize>,
+    skip_rows: Option<usize>,
+    schema: Option<Schema>,
+}
+
+/// A single data type name (e.g. `Int64`, `Datetime`) as it appears in a `schema => '...'`
+/// table-function argument, e.g. `read_csv('f.csv', schema => 'id:Int64, ts:Datetime')`.
+#[cfg(feature = "csv")]
+struct DataTypeName(DataType);
+
+#[cfg(feature = "csv")]
+impl FromStr for DataTypeName {
+    type Err = PolarsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let dtype = match s {
+            "Int8" => DataType::Int8,
+            "Int16" => DataType::Int16,
+            "Int32" => DataType::Int32,
+            "Int64" => DataType::Int64,
+            "UInt8" => DataType::UInt8,
+            "UInt16" => DataType::UInt16,
+            "UInt32" => DataType::UInt32,
+            "UInt64" => DataType::UInt64,
+            "Float32" => DataType::Float32,
+            "Float64" => DataType::Float64,
+            "Boolean" => DataType::Boolean,
+            "Utf8" => DataType::Utf8,
+            "Date" => DataType::Date,
+            "Datetime" => DataType::Datetime(TimeUnit::Microseconds, None),
+            "Time" => DataType::Time,
+            _ => {
+                return Err(PolarsError::ComputeError(
+                    format!("'{}' is not a recognized data type in a schema string", s).into(),
+                ))
+            }
+        };
+        Ok(DataTypeName(dtype))
+    }
+}
+
+/// Options shared by the `read_parquet` and `read_ipc` table functions, parsed from their
+/// named arguments.
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[derive(Default, Clone)]
+struct ScanReadOptions {
+    n_rows: Option<usize>,
+    cache: Option<bool>,
+    rechunk: Option<bool>,
+}
+
 impl PolarsTableFunctions {
     pub(crate) fn execute(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
         match self {
@@ -55,6 +136,10 @@ impl PolarsTableFunctions {
             PolarsTableFunctions::ReadParquet => self.read_parquet(args),
             #[cfg(feature = "ipc")]
             PolarsTableFunctions::ReadIpc => self.read_ipc(args),
+            #[cfg(feature = "json")]
+            PolarsTableFunctions::ReadNdjson => self.read_ndjson(args),
+            #[cfg(feature = "avro")]
+            PolarsTableFunctions::ReadAvro => self.read_avro(args),
             _ => unreachable!(),
         }
     }
@@ -62,25 +147,114 @@ impl PolarsTableFunctions {
     #[cfg(feature = "csv")]
     fn read_csv(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
         use polars_lazy::frame::LazyFileListReader;
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyCsvReader::new(&path).finish()?;
-        Ok((path, lf))
+        let paths = self.get_file_paths_from_arg(&args[0])?;
+        let opts = self.parse_csv_options(&args[1..])?;
+
+        let resolved = self.expand_paths(&paths)?;
+        let lfs = resolved
+            .iter()
+            .map(|path| {
+                let mut reader = LazyCsvReader::new(path);
+                if let Some(delimiter) = opts.delimiter {
+                    reader = reader.with_delimiter(delimiter);
+                }
+                if let Some(has_header) = opts.has_header {
+                    reader = reader.has_header(has_header);
+                }
+                if let Some(infer_schema_length) = opts.infer_schema_length {
+                    reader = reader.with_infer_schema_length(Some(infer_schema_length));
+                }
+                if let Some(skip_rows) = opts.skip_rows {
+                    reader = reader.with_skip_rows(skip_rows);
+                }
+                if let Some(schema) = opts.schema.as_ref() {
+                    reader = reader.with_dtype_overwrite(Some(schema));
+                }
+                reader.finish()
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let lf = self.concat_lazyframes(lfs)?;
+        Ok((paths[0].clone(), lf))
     }
 
     #[cfg(feature = "parquet")]
     fn read_parquet(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_parquet(&path, Default::default())?;
-        Ok((path, lf))
+        let paths = self.get_file_paths_from_arg(&args[0])?;
+        let opts = self.parse_scan_options(&args[1..], "read_parquet")?;
+
+        let mut scan_args = ScanArgsParquet::default();
+        if let Some(n_rows) = opts.n_rows {
+            scan_args.n_rows = Some(n_rows);
+        }
+        if let Some(cache) = opts.cache {
+            scan_args.cache = cache;
+        }
+        if let Some(rechunk) = opts.rechunk {
+            scan_args.rechunk = rechunk;
+        }
+
+        // `scan_parquet` natively expands glob patterns, so each entry in `paths` is
+        // scanned as-is and the results (if there is more than one path) are concatenated.
+        let lfs = paths
+            .iter()
+            .map(|path| LazyFrame::scan_parquet(path, scan_args.clone()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let lf = self.concat_lazyframes(lfs)?;
+        Ok((paths[0].clone(), lf))
     }
 
     #[cfg(feature = "ipc")]
     fn read_ipc(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
+        let paths = self.get_file_paths_from_arg(&args[0])?;
+        let opts = self.parse_scan_options(&args[1..], "read_ipc")?;
+
+        let mut scan_args = ScanArgsIpc::default();
+        if let Some(n_rows) = opts.n_rows {
+            scan_args.n_rows = Some(n_rows);
+        }
+        if let Some(cache) = opts.cache {
+            scan_args.cache = cache;
+        }
+        if let Some(rechunk) = opts.rechunk {
+            scan_args.rechunk = rechunk;
+        }
+
+        // `scan_ipc` natively expands glob patterns, so each entry in `paths` is scanned
+        // as-is and the results (if there is more than one path) are concatenated.
+        let lfs = paths
+            .iter()
+            .map(|path| LazyFrame::scan_ipc(path, scan_args.clone()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let lf = self.concat_lazyframes(lfs)?;
+        Ok((paths[0].clone(), lf))
+    }
+
+    #[cfg(feature = "json")]
+    fn read_ndjson(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
+        use polars_lazy::frame::LazyFileListReader;
+        use polars_lazy::prelude::LazyJsonLineReader;
+
         let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_ipc(&path, Default::default())?;
+        self.ensure_no_extra_args(&args[1..], "read_ndjson")?;
+        let lf = LazyJsonLineReader::new(path.clone()).finish()?;
         Ok((path, lf))
     }
 
+    #[cfg(feature = "avro")]
+    fn read_avro(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
+        use polars_io::avro::AvroReader;
+        use polars_io::SerReader;
+
+        let path = self.get_file_path_from_arg(&args[0])?;
+        self.ensure_no_extra_args(&args[1..], "read_avro")?;
+        let file = std::fs::File::open(&path)?;
+        let df = AvroReader::new(file).finish()?;
+        Ok((path, df.lazy()))
+    }
+
     fn get_file_path_from_arg(&self, arg: &FunctionArg) -> PolarsResult<String> {
         use sqlparser::ast::{Expr as SqlExpr, Value as SqlValue};
         match arg {
@@ -92,4 +266,696 @@ impl PolarsTableFunctions {
             )),
         }
     }
-}
\ No newline at end of file
+
+    /// Reject any arguments beyond the file path, for table functions that accept no options.
+    #[cfg(any(feature = "json", feature = "avro"))]
+    fn ensure_no_extra_args(&self, args: &[FunctionArg], fn_name: &str) -> PolarsResult<()> {
+        if let Some(arg) = args.first() {
+            return Err(PolarsError::ComputeError(
+                format!("'{}' does not accept the argument: {}", fn_name, arg).into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Extract one or more file paths from the first table-function argument. Accepts either
+    /// a single quoted-string path (which may itself be a glob pattern, e.g. `'data/*.parquet'`)
+    /// or an array literal of quoted-string paths (e.g. `['a.parquet', 'b.parquet']`).
+    #[cfg(any(feature = "csv", feature = "parquet", feature = "ipc"))]
+    fn get_file_paths_from_arg(&self, arg: &FunctionArg) -> PolarsResult<Vec<String>> {
+        use sqlparser::ast::{Expr as SqlExpr, Value as SqlValue};
+        match arg {
+            FunctionArg::Unnamed(FunctionArgExpr::Expr(SqlExpr::Value(
+                SqlValue::SingleQuotedString(s),
+            ))) => Ok(vec![s.to_string()]),
+            FunctionArg::Unnamed(FunctionArgExpr::Expr(SqlExpr::Array(array))) => {
+                if array.elem.is_empty() {
+                    return Err(PolarsError::ComputeError(
+                        "An array of paths must not be empty".into(),
+                    ));
+                }
+                array
+                    .elem
+                    .iter()
+                    .map(|elem| match elem {
+                        SqlExpr::Value(SqlValue::SingleQuotedString(s)) => Ok(s.to_string()),
+                        _ => Err(PolarsError::ComputeError(
+                            format!("Expected a quoted string path in the array, instead received: {}", elem).into(),
+                        )),
+                    })
+                    .collect()
+            }
+            _ => Err(PolarsError::ComputeError(
+                format!("Only a single quoted string or an array of quoted strings is accepted as the first parameter. Instead received: {}", arg).into(),
+            )),
+        }
+    }
+
+    /// `true` if `path` contains a glob meta-character (`*`, `?` or `[`).
+    #[cfg(feature = "csv")]
+    fn is_glob_pattern(&self, path: &str) -> bool {
+        path.contains('*') || path.contains('?') || path.contains('[')
+    }
+
+    /// Expand any glob patterns in `paths` into the list of files they match. Plain paths are
+    /// passed through unchanged. Used by readers (like CSV) that have no native glob support.
+    #[cfg(feature = "csv")]
+    fn expand_paths(&self, paths: &[String]) -> PolarsResult<Vec<String>> {
+        let mut resolved = Vec::with_capacity(paths.len());
+        for path in paths {
+            if self.is_glob_pattern(path) {
+                let matches = glob::glob(path)
+                    .map_err(|e| {
+                        PolarsError::ComputeError(format!("invalid glob pattern '{}': {}", path, e).into())
+                    })?
+                    .filter_map(|entry| entry.ok())
+                    .map(|p| p.to_string_lossy().into_owned())
+                    .collect::<Vec<_>>();
+                if matches.is_empty() {
+                    return Err(PolarsError::ComputeError(
+                        format!("no files match pattern '{}'", path).into(),
+                    ));
+                }
+                resolved.extend(matches);
+            } else {
+                resolved.push(path.clone());
+            }
+        }
+        Ok(resolved)
+    }
+
+    /// Concatenate one or more scanned `LazyFrame`s into a single `LazyFrame`.
+    #[cfg(any(feature = "csv", feature = "parquet", feature = "ipc"))]
+    fn concat_lazyframes(&self, mut lfs: Vec<LazyFrame>) -> PolarsResult<LazyFrame> {
+        match lfs.len() {
+            0 => Err(PolarsError::ComputeError("no files to scan".into())),
+            1 => Ok(lfs.remove(0)),
+            _ => concat(&lfs, true, true),
+        }
+    }
+
+    /// Extract the name and literal value of a SQL named argument, e.g. `delimiter => ';'`.
+    #[cfg(any(feature = "csv", feature = "parquet", feature = "ipc"))]
+    fn get_named_arg(&self, arg: &FunctionArg) -> PolarsResult<(String, &sqlparser::ast::Value)> {
+        use sqlparser::ast::{Expr as SqlExpr, Value as SqlValue};
+        match arg {
+            FunctionArg::Named {
+                name,
+                arg: FunctionArgExpr::Expr(SqlExpr::Value(value)),
+            } => Ok((name.value.to_lowercase(), value)),
+            FunctionArg::Named { name, .. } => Err(PolarsError::ComputeError(
+                format!("Argument '{}' must be a literal value", name).into(),
+            )),
+            _ => Err(PolarsError::ComputeError(
+                format!("Only named arguments are accepted after the path. Instead received: {}", arg).into(),
+            )),
+        }
+    }
+
+    #[cfg(any(feature = "csv", feature = "parquet", feature = "ipc"))]
+    fn value_as_string(&self, key: &str, value: &sqlparser::ast::Value) -> PolarsResult<String> {
+        use sqlparser::ast::Value as SqlValue;
+        match value {
+            SqlValue::SingleQuotedString(s) => Ok(s.clone()),
+            _ => Err(PolarsError::ComputeError(
+                format!("Expected a string value for argument '{}', got: {}", key, value).into(),
+            )),
+        }
+    }
+
+    #[cfg(any(feature = "csv", feature = "parquet", feature = "ipc"))]
+    fn value_as_bool(&self, key: &str, value: &sqlparser::ast::Value) -> PolarsResult<bool> {
+        use sqlparser::ast::Value as SqlValue;
+        match value {
+            SqlValue::Boolean(b) => Ok(*b),
+            _ => Err(PolarsError::ComputeError(
+                format!("Expected a boolean value for argument '{}', got: {}", key, value).into(),
+            )),
+        }
+    }
+
+    #[cfg(any(feature = "csv", feature = "parquet", feature = "ipc"))]
+    fn value_as_usize(&self, key: &str, value: &sqlparser::ast::Value) -> PolarsResult<usize> {
+        use sqlparser::ast::Value as SqlValue;
+        match value {
+            SqlValue::Number(s, _) => s.parse::<usize>().map_err(|_| {
+                PolarsError::ComputeError(
+                    format!("Expected an unsigned integer value for argument '{}', got: {}", key, s).into(),
+                )
+            }),
+            _ => Err(PolarsError::ComputeError(
+                format!("Expected a numeric value for argument '{}', got: {}", key, value).into(),
+            )),
+        }
+    }
+
+    #[cfg(feature = "csv")]
+    fn parse_csv_options(&self, args: &[FunctionArg]) -> PolarsResult<CsvReadOptions> {
+        let mut opts = CsvReadOptions::default();
+        for arg in args {
+            let (key, value) = self.get_named_arg(arg)?;
+            match key.as_str() {
+                "delimiter" | "sep" => {
+                    let s = self.value_as_string(&key, value)?;
+                    let mut bytes = s.bytes();
+                    let delimiter = bytes.next().ok_or_else(|| {
+                        PolarsError::ComputeError("'delimiter' cannot be empty".into())
+                    })?;
+                    if bytes.next().is_some() {
+                        return Err(PolarsError::ComputeError(
+                            "'delimiter' must be a single byte".into(),
+                        ));
+                    }
+                    opts.delimiter = Some(delimiter);
+                }
+                "has_header" => opts.has_header = Some(self.value_as_bool(&key, value)?),
+                "infer_schema_length" => {
+                    opts.infer_schema_length = Some(self.value_as_usize(&key, value)?)
+                }
+                "skip_rows" => opts.skip_rows = Some(self.value_as_usize(&key, value)?),
+                "schema" | "dtypes" => {
+                    let s = self.value_as_string(&key, value)?;
+                    opts.schema = Some(self.parse_schema_str(&s)?);
+                }
+                _ => {
+                    return Err(PolarsError::ComputeError(
+                        format!("'{}' is not a recognized argument for read_csv", key).into(),
+                    ))
+                }
+            }
+        }
+        Ok(opts)
+    }
+
+    /// Parse a `'name:type, name:type, ...'` schema string, e.g. `'id:Int64, ts:Datetime'`,
+    /// into a `Schema`.
+    #[cfg(feature = "csv")]
+    fn parse_schema_str(&self, s: &str) -> PolarsResult<Schema> {
+        let mut schema = Schema::new();
+        for field in s.split(',') {
+            let field = field.trim();
+            if field.is_empty() {
+                continue;
+            }
+            let (name, dtype_str) = field.split_once(':').ok_or_else(|| {
+                PolarsError::ComputeError(
+                    format!("invalid schema entry '{}', expected 'name:type'", field).into(),
+                )
+            })?;
+            let dtype = DataTypeName::from_str(dtype_str.trim())?.0;
+            schema.with_column(name.trim().into(), dtype);
+        }
+        Ok(schema)
+    }
+
+    /// Parse the named arguments shared by `read_parquet` and `read_ipc`. `fn_name` is used
+    /// only to name the calling table function in error messages.
+    #[cfg(any(feature = "parquet", feature = "ipc"))]
+    fn parse_scan_options(
+        &self,
+        args: &[FunctionArg],
+        fn_name: &str,
+    ) -> PolarsResult<ScanReadOptions> {
+        let mut opts = ScanReadOptions::default();
+        for arg in args {
+            let (key, value) = self.get_named_arg(arg)?;
+            match key.as_str() {
+                "n_rows" => opts.n_rows = Some(self.value_as_usize(&key, value)?),
+                "cache" => opts.cache = Some(self.value_as_bool(&key, value)?),
+                "rechunk" => opts.rechunk = Some(self.value_as_bool(&key, value)?),
+                _ => {
+                    return Err(PolarsError::ComputeError(
+                        format!("'{}' is not a recognized argument for {}", key, fn_name).into(),
+                    ))
+                }
+            }
+        }
+        Ok(opts)
+    }
+}
+
+/// Shared constructors for building `FunctionArg` values in tests, without going through the
+/// SQL parser.
+#[cfg(test)]
+mod test_helpers {
+    use sqlparser::ast::{Array, Expr as SqlExpr, FunctionArg, FunctionArgExpr, Ident, Value as SqlValue};
+
+    pub(super) fn unnamed_str_arg(s: &str) -> FunctionArg {
+        FunctionArg::Unnamed(FunctionArgExpr::Expr(SqlExpr::Value(
+            SqlValue::SingleQuotedString(s.to_string()),
+        )))
+    }
+
+    pub(super) fn unnamed_array_arg(paths: &[&str]) -> FunctionArg {
+        FunctionArg::Unnamed(FunctionArgExpr::Expr(SqlExpr::Array(Array {
+            elem: paths
+                .iter()
+                .map(|s| SqlExpr::Value(SqlValue::SingleQuotedString(s.to_string())))
+                .collect(),
+            named: false,
+        })))
+    }
+
+    pub(super) fn named_str_arg(name: &str, value: &str) -> FunctionArg {
+        FunctionArg::Named {
+            name: Ident::new(name),
+            arg: FunctionArgExpr::Expr(SqlExpr::Value(SqlValue::SingleQuotedString(
+                value.to_string(),
+            ))),
+        }
+    }
+
+    pub(super) fn named_bool_arg(name: &str, value: bool) -> FunctionArg {
+        FunctionArg::Named {
+            name: Ident::new(name),
+            arg: FunctionArgExpr::Expr(SqlExpr::Value(SqlValue::Boolean(value))),
+        }
+    }
+
+    pub(super) fn named_usize_arg(name: &str, value: usize) -> FunctionArg {
+        FunctionArg::Named {
+            name: Ident::new(name),
+            arg: FunctionArgExpr::Expr(SqlExpr::Value(SqlValue::Number(value.to_string(), false))),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "csv"))]
+mod tests {
+    use super::test_helpers::{named_bool_arg, named_str_arg};
+    use super::*;
+
+    #[test]
+    fn parse_csv_options_applies_named_args() {
+        let tf = PolarsTableFunctions::ReadCsv;
+        let args = vec![
+            named_str_arg("delimiter", ";"),
+            named_bool_arg("has_header", false),
+        ];
+        let opts = tf.parse_csv_options(&args).unwrap();
+        assert_eq!(opts.delimiter, Some(b';'));
+        assert_eq!(opts.has_header, Some(false));
+    }
+
+    #[test]
+    fn parse_csv_options_rejects_multi_byte_delimiter() {
+        let tf = PolarsTableFunctions::ReadCsv;
+        let args = vec![named_str_arg("delimiter", ";;")];
+        assert!(tf.parse_csv_options(&args).is_err());
+    }
+
+    #[test]
+    fn parse_csv_options_rejects_unknown_argument() {
+        let tf = PolarsTableFunctions::ReadCsv;
+        let args = vec![named_str_arg("not_a_real_option", "oops")];
+        assert!(tf.parse_csv_options(&args).is_err());
+    }
+
+    #[test]
+    fn parse_csv_options_applies_schema() {
+        let tf = PolarsTableFunctions::ReadCsv;
+        let args = vec![named_str_arg("schema", "id:Int64, name:Utf8")];
+        let opts = tf.parse_csv_options(&args).unwrap();
+        let schema = opts.schema.unwrap();
+        assert_eq!(schema.get("id"), Some(&DataType::Int64));
+        assert_eq!(schema.get("name"), Some(&DataType::Utf8));
+    }
+}
+
+#[cfg(all(test, feature = "csv"))]
+mod schema_str_tests {
+    use super::*;
+
+    #[test]
+    fn data_type_name_parses_known_types() {
+        assert_eq!(DataTypeName::from_str("Int64").unwrap().0, DataType::Int64);
+        assert_eq!(DataTypeName::from_str("Utf8").unwrap().0, DataType::Utf8);
+        assert_eq!(
+            DataTypeName::from_str("Datetime").unwrap().0,
+            DataType::Datetime(TimeUnit::Microseconds, None)
+        );
+    }
+
+    #[test]
+    fn data_type_name_rejects_unknown_type() {
+        assert!(DataTypeName::from_str("NotARealType").is_err());
+    }
+
+    #[test]
+    fn parse_schema_str_builds_expected_schema() {
+        let tf = PolarsTableFunctions::ReadCsv;
+        let schema = tf
+            .parse_schema_str("id:Int64, ts:Datetime, name:Utf8")
+            .unwrap();
+        assert_eq!(schema.get("id"), Some(&DataType::Int64));
+        assert_eq!(
+            schema.get("ts"),
+            Some(&DataType::Datetime(TimeUnit::Microseconds, None))
+        );
+        assert_eq!(schema.get("name"), Some(&DataType::Utf8));
+    }
+
+    #[test]
+    fn parse_schema_str_rejects_missing_colon() {
+        let tf = PolarsTableFunctions::ReadCsv;
+        assert!(tf.parse_schema_str("id Int64").is_err());
+    }
+
+    #[test]
+    fn parse_schema_str_rejects_unknown_type() {
+        let tf = PolarsTableFunctions::ReadCsv;
+        assert!(tf.parse_schema_str("id:NotARealType").is_err());
+    }
+
+    #[test]
+    fn parse_schema_str_ignores_blank_entries() {
+        let tf = PolarsTableFunctions::ReadCsv;
+        let schema = tf.parse_schema_str("id:Int64,").unwrap();
+        assert_eq!(schema.len(), 1);
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod ndjson_tests {
+    use sqlparser::ast::{Expr as SqlExpr, Ident, Value as SqlValue};
+
+    use super::*;
+
+    #[test]
+    fn ensure_no_extra_args_rejects_unknown_argument() {
+        let tf = PolarsTableFunctions::ReadNdjson;
+        let extra = FunctionArg::Named {
+            name: Ident::new("has_header"),
+            arg: FunctionArgExpr::Expr(SqlExpr::Value(SqlValue::Boolean(true))),
+        };
+        assert!(tf.ensure_no_extra_args(&[extra], "read_ndjson").is_err());
+    }
+
+    #[test]
+    fn ensure_no_extra_args_accepts_no_args() {
+        let tf = PolarsTableFunctions::ReadNdjson;
+        assert!(tf.ensure_no_extra_args(&[], "read_ndjson").is_ok());
+    }
+}
+
+#[cfg(all(test, feature = "csv"))]
+mod path_arg_tests {
+    use super::test_helpers::unnamed_array_arg;
+    use super::test_helpers::unnamed_str_arg;
+    use super::*;
+
+    #[test]
+    fn get_file_paths_from_arg_single_path() {
+        let tf = PolarsTableFunctions::ReadCsv;
+        let paths = tf
+            .get_file_paths_from_arg(&unnamed_str_arg("data/f.csv"))
+            .unwrap();
+        assert_eq!(paths, vec!["data/f.csv".to_string()]);
+    }
+
+    #[test]
+    fn get_file_paths_from_arg_array_of_paths() {
+        let tf = PolarsTableFunctions::ReadCsv;
+        let paths = tf
+            .get_file_paths_from_arg(&unnamed_array_arg(&["a.csv", "b.csv"]))
+            .unwrap();
+        assert_eq!(paths, vec!["a.csv".to_string(), "b.csv".to_string()]);
+    }
+
+    #[test]
+    fn get_file_paths_from_arg_rejects_empty_array() {
+        let tf = PolarsTableFunctions::ReadCsv;
+        assert!(tf.get_file_paths_from_arg(&unnamed_array_arg(&[])).is_err());
+    }
+
+    #[test]
+    fn is_glob_pattern_detects_wildcards() {
+        let tf = PolarsTableFunctions::ReadCsv;
+        assert!(tf.is_glob_pattern("data/*.csv"));
+        assert!(tf.is_glob_pattern("data/part-?.csv"));
+        assert!(tf.is_glob_pattern("data/[abc].csv"));
+        assert!(!tf.is_glob_pattern("data/f.csv"));
+    }
+
+    #[test]
+    fn expand_paths_errors_on_zero_matches() {
+        let tf = PolarsTableFunctions::ReadCsv;
+        let paths = vec!["definitely/does/not/exist/*.csv".to_string()];
+        assert!(tf.expand_paths(&paths).is_err());
+    }
+
+    #[test]
+    fn expand_paths_passes_through_non_glob_paths() {
+        let tf = PolarsTableFunctions::ReadCsv;
+        let paths = vec!["plain/path.csv".to_string()];
+        assert_eq!(tf.expand_paths(&paths).unwrap(), paths);
+    }
+}
+
+/// Returns a path under the system temp directory that is unique to this test process and
+/// `name`, so parallel test runs don't collide.
+#[cfg(any(
+    all(test, feature = "csv"),
+    all(test, feature = "parquet"),
+    all(test, feature = "ipc"),
+    all(test, feature = "json"),
+    all(test, feature = "avro")
+))]
+fn test_temp_path(name: &str, extension: &str) -> std::path::PathBuf {
+    let mut path = std::env::temp_dir();
+    path.push(format!(
+        "polars_sql_table_fn_test_{}_{}.{}",
+        name,
+        std::process::id(),
+        extension
+    ));
+    path
+}
+
+#[cfg(all(test, feature = "csv"))]
+mod csv_io_tests {
+    use super::test_helpers::{named_str_arg, named_usize_arg, unnamed_array_arg, unnamed_str_arg};
+    use super::*;
+
+    #[test]
+    fn read_csv_applies_delimiter_and_skip_rows() {
+        let path = test_temp_path("csv_delim_skip", "csv");
+        std::fs::write(&path, "this line is skipped\nid;name\n1;a\n2;b\n").unwrap();
+
+        let args = vec![
+            unnamed_str_arg(path.to_str().unwrap()),
+            named_str_arg("delimiter", ";"),
+            named_usize_arg("skip_rows", 1),
+        ];
+        let tf = PolarsTableFunctions::ReadCsv;
+        let (_, lf) = tf.read_csv(&args).unwrap();
+        let df = lf.collect().unwrap();
+
+        assert_eq!(df.height(), 2);
+        assert_eq!(df.get_column_names(), vec!["id", "name"]);
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn read_csv_applies_schema_override() {
+        // Without a schema override, `code` would infer as Int64 and `flag` as Boolean.
+        // The `schema` argument should force them to Utf8 instead.
+        let path = test_temp_path("csv_schema_override", "csv");
+        std::fs::write(&path, "code,flag\n007,true\n042,false\n").unwrap();
+
+        let args = vec![
+            unnamed_str_arg(path.to_str().unwrap()),
+            named_str_arg("schema", "code:Utf8, flag:Utf8"),
+        ];
+        let tf = PolarsTableFunctions::ReadCsv;
+        let (_, lf) = tf.read_csv(&args).unwrap();
+        let df = lf.collect().unwrap();
+
+        assert_eq!(df.column("code").unwrap().dtype(), &DataType::Utf8);
+        assert_eq!(df.column("flag").unwrap().dtype(), &DataType::Utf8);
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn read_csv_expands_glob_and_concatenates() {
+        let tag = format!("csv_glob_{}", std::process::id());
+        let dir = std::env::temp_dir();
+        let path_a = dir.join(format!("polars_sql_table_fn_test_{tag}_a.csv"));
+        let path_b = dir.join(format!("polars_sql_table_fn_test_{tag}_b.csv"));
+        std::fs::write(&path_a, "id\n1\n2\n").unwrap();
+        std::fs::write(&path_b, "id\n3\n").unwrap();
+
+        let pattern = dir.join(format!("polars_sql_table_fn_test_{tag}_*.csv"));
+        let args = vec![unnamed_str_arg(pattern.to_str().unwrap())];
+        let tf = PolarsTableFunctions::ReadCsv;
+        let (alias, lf) = tf.read_csv(&args).unwrap();
+        let df = lf.collect().unwrap();
+
+        assert_eq!(df.height(), 3);
+        assert_eq!(alias, pattern.to_str().unwrap());
+
+        std::fs::remove_file(&path_a).ok();
+        std::fs::remove_file(&path_b).ok();
+    }
+
+    #[test]
+    fn read_csv_accepts_array_of_paths() {
+        let path_a = test_temp_path("csv_array_a", "csv");
+        let path_b = test_temp_path("csv_array_b", "csv");
+        std::fs::write(&path_a, "id\n1\n2\n").unwrap();
+        std::fs::write(&path_b, "id\n3\n").unwrap();
+
+        let args = vec![unnamed_array_arg(&[
+            path_a.to_str().unwrap(),
+            path_b.to_str().unwrap(),
+        ])];
+        let tf = PolarsTableFunctions::ReadCsv;
+        let (alias, lf) = tf.read_csv(&args).unwrap();
+        let df = lf.collect().unwrap();
+
+        assert_eq!(df.height(), 3);
+        assert_eq!(alias, path_a.to_str().unwrap());
+
+        std::fs::remove_file(&path_a).ok();
+        std::fs::remove_file(&path_b).ok();
+    }
+}
+
+#[cfg(all(test, feature = "parquet"))]
+mod parquet_io_tests {
+    use polars_io::prelude::ParquetWriter;
+    use polars_io::SerWriter;
+
+    use super::test_helpers::{named_bool_arg, named_usize_arg, unnamed_array_arg, unnamed_str_arg};
+    use super::*;
+
+    fn write_parquet(path: &std::path::Path, ids: &[i64]) {
+        let mut df = polars_core::df!["id" => ids].unwrap();
+        let file = std::fs::File::create(path).unwrap();
+        ParquetWriter::new(file).finish(&mut df).unwrap();
+    }
+
+    #[test]
+    fn read_parquet_applies_n_rows_and_cache() {
+        let path = test_temp_path("parquet_n_rows", "parquet");
+        write_parquet(&path, &[1, 2, 3, 4, 5]);
+
+        let args = vec![
+            unnamed_str_arg(path.to_str().unwrap()),
+            named_usize_arg("n_rows", 2),
+            named_bool_arg("cache", false),
+        ];
+        let tf = PolarsTableFunctions::ReadParquet;
+        let (_, lf) = tf.read_parquet(&args).unwrap();
+        let df = lf.collect().unwrap();
+
+        assert_eq!(df.height(), 2);
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn read_parquet_accepts_array_of_paths_and_concatenates() {
+        let path_a = test_temp_path("parquet_array_a", "parquet");
+        let path_b = test_temp_path("parquet_array_b", "parquet");
+        write_parquet(&path_a, &[1, 2]);
+        write_parquet(&path_b, &[3]);
+
+        let args = vec![unnamed_array_arg(&[
+            path_a.to_str().unwrap(),
+            path_b.to_str().unwrap(),
+        ])];
+        let tf = PolarsTableFunctions::ReadParquet;
+        let (alias, lf) = tf.read_parquet(&args).unwrap();
+        let df = lf.collect().unwrap();
+
+        assert_eq!(df.height(), 3);
+        assert_eq!(alias, path_a.to_str().unwrap());
+
+        std::fs::remove_file(&path_a).ok();
+        std::fs::remove_file(&path_b).ok();
+    }
+}
+
+#[cfg(all(test, feature = "ipc"))]
+mod ipc_io_tests {
+    use polars_io::prelude::IpcWriter;
+    use polars_io::SerWriter;
+
+    use super::test_helpers::{named_usize_arg, unnamed_str_arg};
+    use super::*;
+
+    fn write_ipc(path: &std::path::Path, ids: &[i64]) {
+        let mut df = polars_core::df!["id" => ids].unwrap();
+        let file = std::fs::File::create(path).unwrap();
+        IpcWriter::new(file).finish(&mut df).unwrap();
+    }
+
+    #[test]
+    fn read_ipc_applies_n_rows() {
+        let path = test_temp_path("ipc_n_rows", "ipc");
+        write_ipc(&path, &[1, 2, 3, 4, 5]);
+
+        let args = vec![
+            unnamed_str_arg(path.to_str().unwrap()),
+            named_usize_arg("n_rows", 3),
+        ];
+        let tf = PolarsTableFunctions::ReadIpc;
+        let (_, lf) = tf.read_ipc(&args).unwrap();
+        let df = lf.collect().unwrap();
+
+        assert_eq!(df.height(), 3);
+
+        std::fs::remove_file(&path).ok();
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod ndjson_io_tests {
+    use super::test_helpers::unnamed_str_arg;
+    use super::*;
+
+    #[test]
+    fn read_ndjson_reads_rows() {
+        let path = test_temp_path("ndjson_rows", "ndjson");
+        std::fs::write(&path, "{\"id\": 1}\n{\"id\": 2}\n").unwrap();
+
+        let args = vec![unnamed_str_arg(path.to_str().unwrap())];
+        let tf = PolarsTableFunctions::ReadNdjson;
+        let (_, lf) = tf.read_ndjson(&args).unwrap();
+        let df = lf.collect().unwrap();
+
+        assert_eq!(df.height(), 2);
+
+        std::fs::remove_file(&path).ok();
+    }
+}
+
+#[cfg(all(test, feature = "avro"))]
+mod avro_io_tests {
+    use polars_io::avro::AvroWriter;
+    use polars_io::SerWriter;
+
+    use super::test_helpers::unnamed_str_arg;
+    use super::*;
+
+    #[test]
+    fn read_avro_reads_rows() {
+        let path = test_temp_path("avro_rows", "avro");
+
+        let mut df = polars_core::df!["id" => [1i64, 2, 3]].unwrap();
+        let file = std::fs::File::create(&path).unwrap();
+        AvroWriter::new(file).finish(&mut df).unwrap();
+
+        let args = vec![unnamed_str_arg(path.to_str().unwrap())];
+        let tf = PolarsTableFunctions::ReadAvro;
+        let (_, lf) = tf.read_avro(&args).unwrap();
+        let collected = lf.collect().unwrap();
+
+        assert_eq!(collected.height(), 3);
+
+        std::fs::remove_file(&path).ok();
+    }
+}